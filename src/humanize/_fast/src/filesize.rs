@@ -22,7 +22,7 @@ fn extract_to_extracted(obj: &Bound<'_, PyAny>) -> Extracted {
         Extracted::Numeric(i as f64)
     } else if let Ok(s) = obj.extract::<String>() {
         if let Some(parsed) = parse_value(&s) {
-            Extracted::Numeric(parsed)
+            Extracted::Numeric(parsed.to_f64())
         } else {
             Extracted::Raw(s)
         }
@@ -154,6 +154,7 @@ pub fn naturalsize(
             return Ok(norm.to_object(py));
         }
         if let Some(parsed) = parse_value(&s) {
+            let parsed = parsed.to_f64();
             if !parsed.is_finite() {
                 return Ok(format_not_finite(parsed).to_object(py));
             }
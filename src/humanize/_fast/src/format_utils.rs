@@ -2,7 +2,10 @@
 //!
 //! Helper functions for formatting numbers/filesizes.
 
+use num_bigint::BigInt;
+use num_traits::ToPrimitive;
 use regex::Regex;
+use std::str::FromStr;
 
 /// Suffix tables
 pub static SUFFIXES_DECIMAL: [&str; 10] = [
@@ -15,12 +18,115 @@ pub static SUFFIXES_GNU: &str = "KMGTPEZYRQ";
 
 lazy_static::lazy_static! {
     static ref RE_FLOAT_FORMAT: Regex = Regex::new(r"%\.(\d+)f").unwrap();
+    static ref RE_PRINTF_PRECISION: Regex = Regex::new(r"%[-+ #0]*\d*\.(\d+)f").unwrap();
+    static ref RE_INTEGER: Regex = Regex::new(r"^[+-]?\d+$").unwrap();
+    static ref RE_NUMERIC_PREFIX: Regex =
+        Regex::new(r"^[+-]?(?:0[xX][0-9a-fA-F]+|0[bB][01]+|\d+(?:\.\d+)?(?:[eE][+-]?\d+)?)")
+            .unwrap();
 }
 
-/// Safely parse numeric-like strings to f64
-pub fn parse_value(value: &str) -> Option<f64> {
-    let cleaned = value.replace(',', "");
-    cleaned.parse::<f64>().ok()
+/// Result of parsing a numeric-like string: an exact integer (kept as
+/// `BigInt` so values beyond `f64`'s precision aren't corrupted) or a float.
+pub enum ParsedNumber {
+    Integer(BigInt),
+    Float(f64),
+}
+
+impl ParsedNumber {
+    /// Lossy `f64` view, for callers that only need an approximate magnitude
+    /// (e.g. `naturalsize`'s byte-size math).
+    pub fn to_f64(&self) -> f64 {
+        match self {
+            ParsedNumber::Integer(n) => n.to_f64().unwrap_or(f64::NAN),
+            ParsedNumber::Float(f) => *f,
+        }
+    }
+}
+
+/// Unit-like suffixes `parse_value` will strip from a numeric prefix. This is
+/// deliberately a closed list rather than "anything non-numeric" — free text
+/// that happens to start with digits (`"3 cats"`, `"123 Main St"`) must keep
+/// echoing the original string, not get silently reinterpreted as a bare
+/// number.
+static KNOWN_UNIT_SUFFIXES: [&str; 27] = [
+    // Bare SI/GNU magnitude letters, as used by `intword`/`SUFFIXES_GNU`.
+    "K", "k", "M", "G", "T", "P", "E", "Z", "Y",
+    // Byte-size units: all of `SUFFIXES_DECIMAL`, and `SUFFIXES_BINARY` up to "TiB".
+    "B", "kB", "MB", "GB", "TB", "PB", "EB", "ZB", "YB", "RB", "QB", "KiB", "MiB", "GiB", "TiB",
+    // Common mass units, as called out by the original request's "100 kg" example.
+    "g", "kg", "mg",
+];
+
+/// Whether `suffix` (the leftover text after a numeric prefix) is a
+/// recognized unit, modulo leading whitespace (`"100 kg"` -> `" kg"` -> `"kg"`).
+fn is_known_unit_suffix(suffix: &str) -> bool {
+    KNOWN_UNIT_SUFFIXES.contains(&suffix.trim_start())
+}
+
+/// Safely parse numeric-like strings into an exact integer or float.
+///
+/// Accepts `,`/`_` digit separators, an explicit leading `+`, and `0x`/`0b`
+/// integer literals, in addition to plain decimal and scientific-notation
+/// floats. Integers are returned exactly (as `BigInt`) so huge values don't
+/// get rounded away by `f64` before formatting. A trailing unit suffix (e.g.
+/// `"100K"` or `"100 kg"`) is stripped when it matches a known SI/byte-unit
+/// token — see `KNOWN_UNIT_SUFFIXES` — never for arbitrary trailing text.
+pub fn parse_value(value: &str) -> Option<ParsedNumber> {
+    let cleaned: String = value
+        .trim()
+        .chars()
+        .filter(|&c| c != ',' && c != '_')
+        .collect();
+    if cleaned.is_empty() {
+        return None;
+    }
+
+    if let Some(parsed) = parse_numeric_core(&cleaned) {
+        return Some(parsed);
+    }
+
+    let prefix_len = RE_NUMERIC_PREFIX.find(&cleaned)?.end();
+    let (numeric, suffix) = cleaned.split_at(prefix_len);
+    if !is_known_unit_suffix(suffix) {
+        return None;
+    }
+    parse_numeric_core(numeric)
+}
+
+/// Core numeric-literal grammar shared by `parse_value`'s direct and
+/// trailing-noise-stripped attempts: sign, `0x`/`0b` integer literals, plain
+/// decimal integers, and decimal/scientific floats.
+fn parse_numeric_core(cleaned: &str) -> Option<ParsedNumber> {
+    let negative = cleaned.starts_with('-');
+    let unsigned = if negative {
+        &cleaned[1..]
+    } else {
+        cleaned.strip_prefix('+').unwrap_or(cleaned)
+    };
+
+    for (prefix, radix) in [("0x", 16), ("0X", 16), ("0b", 2), ("0B", 2)] {
+        if let Some(digits) = unsigned.strip_prefix(prefix) {
+            let n = BigInt::parse_bytes(digits.as_bytes(), radix)?;
+            return Some(ParsedNumber::Integer(if negative { -n } else { n }));
+        }
+    }
+
+    if RE_INTEGER.is_match(cleaned) {
+        return BigInt::from_str(cleaned).ok().map(ParsedNumber::Integer);
+    }
+
+    cleaned.parse::<f64>().ok().map(ParsedNumber::Float)
+}
+
+/// Extract the decimal-place count from a printf-style `%.Nf` format spec;
+/// flags or a width before the dot are allowed (`"%0.3f"` -> 3). Falls back
+/// to 1 to match the default `"%.1f"`.
+pub fn printf_precision(format_spec: &str) -> usize {
+    RE_PRINTF_PRECISION
+        .captures(format_spec)
+        .and_then(|caps| caps.get(1))
+        .and_then(|m| m.as_str().parse::<usize>().ok())
+        .unwrap_or(1)
 }
 
 /// Normalize string representations of special float values to Python-style capitalization
@@ -48,15 +154,60 @@ pub fn format_not_finite(v: f64) -> String {
     }
 }
 
-/// Insert commas into an integer string
-pub fn add_commas(whole: &str) -> String {
+/// Digit-grouping and separator configuration for locale-aware number
+/// formatting, as used by `intcomma`/`intword`.
+#[derive(Clone)]
+pub struct GroupingScheme {
+    /// Group sizes consumed left-from-the-right; the last entry repeats for
+    /// all remaining digits. `[3]` is the Western system, `[3, 2]` is the
+    /// Indian system (lakh/crore).
+    pub groups: Vec<usize>,
+    pub thousands_sep: String,
+    pub decimal_sep: String,
+}
+
+impl GroupingScheme {
+    /// Resolve a named preset (`"western"`, `"indian"`, `"european"`), with
+    /// optional separator overrides, e.g. a thin space for `"1 234 567"`.
+    /// Unrecognized names fall back to `"western"`.
+    pub fn resolve(scheme: &str, thousands_sep: Option<&str>, decimal_sep: Option<&str>) -> Self {
+        let (groups, default_thousands, default_decimal): (Vec<usize>, &str, &str) =
+            match scheme.to_ascii_lowercase().as_str() {
+                "indian" => (vec![3, 2], ",", "."),
+                "european" => (vec![3], ".", ","),
+                _ => (vec![3], ",", "."),
+            };
+        GroupingScheme {
+            groups,
+            thousands_sep: thousands_sep.unwrap_or(default_thousands).to_string(),
+            decimal_sep: decimal_sep.unwrap_or(default_decimal).to_string(),
+        }
+    }
+}
+
+impl Default for GroupingScheme {
+    fn default() -> Self {
+        GroupingScheme::resolve("western", None, None)
+    }
+}
+
+/// Insert `scheme`'s thousands separator into an integer string, grouping
+/// digits per `scheme.groups` (see `GroupingScheme`).
+pub fn group_digits(whole: &str, scheme: &GroupingScheme) -> String {
     let chars: Vec<char> = whole.chars().collect();
     let mut result = String::new();
     let mut count = 0;
+    let mut group_idx = 0;
+    let mut current_group = scheme.groups.first().copied().unwrap_or(3);
     for &c in chars.iter().rev() {
-        if count == 3 {
-            result.push(',');
+        if count == current_group {
+            result.extend(scheme.thousands_sep.chars().rev());
             count = 0;
+            group_idx += 1;
+            current_group = *scheme
+                .groups
+                .get(group_idx)
+                .unwrap_or_else(|| scheme.groups.last().unwrap_or(&3));
         }
         result.push(c);
         count += 1;
@@ -75,3 +226,30 @@ pub fn apply_printf_style(format_spec: &str, value: f64) -> String {
     }
     value.to_string()
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_value_strips_known_unit_suffix_but_not_free_text() {
+        assert!(matches!(parse_value("100K"), Some(ParsedNumber::Integer(_))));
+        assert!(matches!(parse_value("100 kg"), Some(ParsedNumber::Integer(_))));
+        assert!(parse_value("3 cats").is_none());
+        assert!(parse_value("42 is the answer").is_none());
+        assert!(parse_value("123 Main St").is_none());
+    }
+
+    #[test]
+    fn group_digits_western_scheme_groups_by_three() {
+        let scheme = GroupingScheme::default();
+        assert_eq!(group_digits("1234567", &scheme), "1,234,567");
+    }
+
+    #[test]
+    fn group_digits_indian_scheme_groups_by_lakh_crore() {
+        let scheme = GroupingScheme::resolve("indian", None, None);
+        assert_eq!(group_digits("1234567", &scheme), "12,34,567");
+        assert_eq!(group_digits("100000000", &scheme), "10,00,00,000");
+    }
+}
@@ -14,6 +14,7 @@ fn _fast(_py: Python<'_>, m: &Bound<'_, PyModule>) -> PyResult<()> {
     // Add PyO3 functions from each submodule
     m.add_function(wrap_pyfunction_bound!(intcomma, m)?)?;
     m.add_function(wrap_pyfunction_bound!(intword, m)?)?;
+    m.add_function(wrap_pyfunction_bound!(scientific, m)?)?;
     m.add_function(wrap_pyfunction_bound!(naturalsize, m)?)?;
     Ok(())
 }
@@ -1,3 +1,5 @@
+use num_bigint::BigInt;
+use num_traits::{Signed, Zero};
 use pyo3::prelude::*;
 use pyo3::types::{PyAny, PyList, PyTuple};
 use pyo3::{Bound, Python};
@@ -5,7 +7,8 @@ use rayon::prelude::*;
 use std::fmt::Write;
 
 use crate::format_utils::{
-    add_commas, apply_printf_style, format_not_finite, normalize_special_values, parse_value,
+    apply_printf_style, format_not_finite, group_digits, normalize_special_values, parse_value,
+    printf_precision, GroupingScheme, ParsedNumber,
 };
 
 const POWERS: [f64; 12] = [
@@ -27,19 +30,47 @@ const HUMAN_POWERS: [&str; 12] = [
     "googol",
 ];
 
+lazy_static::lazy_static! {
+    /// Exact power-of-ten thresholds mirroring `POWERS`, kept as `BigInt` so
+    /// `intword` can classify arbitrarily large integers without losing
+    /// precision to `f64`.
+    static ref BIGINT_POWERS: Vec<BigInt> = {
+        let mut powers: Vec<BigInt> = (1..=11u32).map(|k| BigInt::from(10).pow(3 * k)).collect();
+        powers.push(BigInt::from(10).pow(100));
+        powers
+    };
+}
+
 /// Core logic for formatting a single string value.
-fn format_single_value(val_str: String, ndigits: Option<usize>) -> String {
+fn format_single_value(
+    val_str: String,
+    ndigits: Option<usize>,
+    scheme: &GroupingScheme,
+) -> String {
     // 1. Normalize explicitly stringified "inf", "-inf", "nan"
     if let Some(normalized) = normalize_special_values(&val_str) {
         return normalized;
     }
 
     // 2. Parse numeric value
-    let value_num = match parse_value(&val_str) {
+    let parsed = match parse_value(&val_str) {
         Some(v) => v,
         None => return val_str, // Return original string if not numeric
     };
 
+    // 2b. Arbitrary-precision integer fast path: skip f64 entirely so huge
+    // integer strings keep every digit instead of being corrupted by
+    // f64::to_string's rounding or scientific notation.
+    if let (ParsedNumber::Integer(n), None) = (&parsed, ndigits) {
+        let mut whole = group_digits(&n.abs().to_string(), scheme);
+        if n.is_negative() {
+            whole.insert(0, '-');
+        }
+        return whole;
+    }
+
+    let value_num = parsed.to_f64();
+
     // 3. Handle non-finite
     if !value_num.is_finite() {
         return format_not_finite(value_num);
@@ -61,7 +92,7 @@ fn format_single_value(val_str: String, ndigits: Option<usize>) -> String {
         (parts[0], false)
     };
 
-    let mut whole = add_commas(whole_str);
+    let mut whole = group_digits(whole_str, scheme);
     if is_negative {
         whole.insert(0, '-');
     }
@@ -76,7 +107,7 @@ fn format_single_value(val_str: String, ndigits: Option<usize>) -> String {
     let mut result = String::new();
     write!(&mut result, "{}", whole).unwrap();
     if let Some(f) = fraction {
-        write!(&mut result, ".{}", f).unwrap();
+        write!(&mut result, "{}{}", scheme.decimal_sep, f).unwrap();
     }
 
     result
@@ -96,12 +127,27 @@ fn format_single_value(val_str: String, ndigits: Option<usize>) -> String {
 /// '-Inf'
 /// >>> _fast.intcomma(["1234567", "-inf", "nan"])
 /// ['1,234,567', '-Inf', 'NaN']
-#[pyfunction(signature = (value, ndigits=None))]
+/// >>> _fast.intcomma("1234567", scheme="indian")
+/// '12,34,567'
+/// >>> _fast.intcomma("1234567.89", scheme="european")
+/// '1.234.567,89'
+/// >>> _fast.intcomma("1_000_000")
+/// '1,000,000'
+/// >>> _fast.intcomma("0xFF")
+/// '255'
+/// >>> _fast.intcomma("100K")
+/// '100'
+#[pyfunction(signature = (value, ndigits=None, scheme="western", thousands_sep=None, decimal_sep=None))]
 pub fn intcomma(
     py: Python<'_>,
     value: &Bound<'_, PyAny>,
     ndigits: Option<usize>,
+    scheme: &str,
+    thousands_sep: Option<&str>,
+    decimal_sep: Option<&str>,
 ) -> PyResult<PyObject> {
+    let scheme = GroupingScheme::resolve(scheme, thousands_sep, decimal_sep);
+
     // Helper closure to convert any element in the iterable to a Rust String
     let element_to_string = |val: &Bound<'_, PyAny>| -> String {
         if let Ok(s) = val.extract::<String>() {
@@ -125,7 +171,7 @@ pub fn intcomma(
             iterable.iter().map(|val| element_to_string(&val)).collect();
         let results: Vec<String> = string_values
             .into_par_iter()
-            .map(|val_str| format_single_value(val_str, ndigits))
+            .map(|val_str| format_single_value(val_str, ndigits, &scheme))
             .collect();
         return Ok(results.to_object(py));
     }
@@ -136,7 +182,7 @@ pub fn intcomma(
             iterable.iter().map(|val| element_to_string(&val)).collect();
         let results: Vec<String> = string_values
             .into_par_iter()
-            .map(|val_str| format_single_value(val_str, ndigits))
+            .map(|val_str| format_single_value(val_str, ndigits, &scheme))
             .collect();
         return Ok(PyTuple::new_bound(py, results).to_object(py));
     }
@@ -157,22 +203,98 @@ pub fn intcomma(
             .to_object(py));
     };
 
-    let result = format_single_value(val_str, ndigits);
+    let result = format_single_value(val_str, ndigits, &scheme);
     Ok(result.to_object(py))
 }
 
-fn intword_single(val_str: String, format_spec: &str) -> String {
+/// Round `n / divisor` to `precision` decimal digits, returning the mantissa
+/// scaled up by `10^precision` as an exact integer (e.g. `12.4` at precision
+/// 1 -> `124`). Examines one extra digit past the last kept place to decide
+/// the rounding direction, using exact `BigInt` arithmetic throughout so huge
+/// values never round through `f64`.
+fn round_ratio_bigint(n: &BigInt, divisor: &BigInt, precision: usize) -> BigInt {
+    let scale = BigInt::from(10).pow(precision as u32 + 1);
+    let extended = (n * scale) / divisor;
+    let next_digit = &extended % 10;
+    let mut scaled = &extended / 10;
+    if next_digit >= BigInt::from(5) {
+        scaled += 1;
+    }
+    scaled
+}
+
+/// Arbitrary-precision `intword` path for exact integers, used so huge
+/// values (beyond f64's exact integer range) keep every digit instead of
+/// being rounded before the power-of-ten comparison.
+fn intword_single_bigint(n: &BigInt, format_spec: &str, scheme: &GroupingScheme) -> String {
+    let negative_prefix = if n.is_negative() { "-" } else { "" };
+    let abs = n.abs();
+
+    if abs < BIGINT_POWERS[0] {
+        return format!(
+            "{}{}",
+            negative_prefix,
+            group_digits(&abs.to_string(), scheme)
+        );
+    }
+
+    let precision = printf_precision(format_spec);
+
+    for (i, power) in BIGINT_POWERS.iter().enumerate().skip(1) {
+        if &abs < power {
+            let prev = &BIGINT_POWERS[i - 1];
+            let powers_diff = power / prev;
+            let scaled = round_ratio_bigint(&abs, prev, precision);
+
+            // Detect if rounding overflows (e.g., "999.95 thousand" rounds up
+            // to the next tier, "1.0 million") using exact BigInt comparison.
+            if scaled == &powers_diff * BigInt::from(10).pow(precision as u32) {
+                let scaled2 = round_ratio_bigint(&abs, power, precision);
+                let formatted2 = format_scaled_bigint(&scaled2, precision);
+                return format!("{}{} {}", negative_prefix, formatted2, HUMAN_POWERS[i]);
+            }
+
+            let formatted = format_scaled_bigint(&scaled, precision);
+            return format!("{}{} {}", negative_prefix, formatted, HUMAN_POWERS[i - 1]);
+        }
+    }
+
+    // Beyond googol — return the raw number
+    format!("{}{}", negative_prefix, abs)
+}
+
+/// Render an integer scaled up by `10^precision` (as produced by
+/// `intword_single_bigint`'s rounding) back into a fixed-point decimal string.
+fn format_scaled_bigint(scaled: &BigInt, precision: usize) -> String {
+    if precision == 0 {
+        return scaled.to_string();
+    }
+    let unit = BigInt::from(10).pow(precision as u32);
+    let whole = scaled / &unit;
+    let frac = (scaled % &unit).to_string();
+    format!("{}.{:0>width$}", whole, frac, width = precision)
+}
+
+fn intword_single(val_str: String, format_spec: &str, scheme: &GroupingScheme) -> String {
     // Normalize special float strings
     if let Some(normalized) = normalize_special_values(&val_str) {
         return normalized;
     }
 
     // Attempt to parse
-    let value_num = match parse_value(&val_str) {
+    let parsed = match parse_value(&val_str) {
         Some(v) => v,
         None => return val_str,
     };
 
+    // Arbitrary-precision integer fast path, so values beyond f64's exact
+    // integer range (e.g. 2^53) don't get corrupted before formatting.
+    if let ParsedNumber::Integer(n) = &parsed {
+        return intword_single_bigint(n, format_spec, scheme);
+    }
+
+    let value_num = parsed.to_f64();
+
     // Handle NaN/Inf
     if !value_num.is_finite() {
         return format_not_finite(value_num);
@@ -191,7 +313,7 @@ fn intword_single(val_str: String, format_spec: &str) -> String {
         return format!(
             "{}{}",
             negative_prefix,
-            add_commas(&value.trunc().to_string())
+            group_digits(&value.trunc().to_string(), scheme)
         );
     }
 
@@ -201,7 +323,9 @@ fn intword_single(val_str: String, format_spec: &str) -> String {
             let chopped = value / POWERS[i - 1];
             let powers_diff = POWERS[i] / POWERS[i - 1];
             let formatted = apply_printf_style(format_spec, chopped);
-            let formatted_f = parse_value(&formatted).unwrap_or(chopped);
+            let formatted_f = parse_value(&formatted)
+                .map(|p| p.to_f64())
+                .unwrap_or(chopped);
 
             // Detect if rounding overflows (e.g., "1000.0 thousand" → "1.0 million")
             if (formatted_f - powers_diff).abs() < f64::EPSILON {
@@ -239,8 +363,21 @@ fn intword_single(val_str: String, format_spec: &str) -> String {
 /// '1.234 million'
 /// >>> _fast.intword([100, 12400, "1000000"])
 /// ['100', '12.4 thousand', '1.0 million']
-#[pyfunction(signature = (value, format="%.1f"))]
-pub fn intword(py: Python<'_>, value: &Bound<'_, PyAny>, format: &str) -> PyResult<PyObject> {
+/// >>> _fast.intword("123456789012345678901234567890")
+/// '123.5 octillion'
+/// >>> _fast.intword("0xFFFFFFFF")
+/// '4.3 billion'
+#[pyfunction(signature = (value, format="%.1f", scheme="western", thousands_sep=None, decimal_sep=None))]
+pub fn intword(
+    py: Python<'_>,
+    value: &Bound<'_, PyAny>,
+    format: &str,
+    scheme: &str,
+    thousands_sep: Option<&str>,
+    decimal_sep: Option<&str>,
+) -> PyResult<PyObject> {
+    let scheme = GroupingScheme::resolve(scheme, thousands_sep, decimal_sep);
+
     // Convert scalar or iterable, parallelize if possible
     if let Ok(iterable) = value.downcast::<PyList>() {
         let string_values: Vec<String> = iterable
@@ -249,7 +386,185 @@ pub fn intword(py: Python<'_>, value: &Bound<'_, PyAny>, format: &str) -> PyResu
             .collect();
         let results: Vec<String> = string_values
             .into_par_iter()
-            .map(|val| intword_single(val, format))
+            .map(|val| intword_single(val, format, &scheme))
+            .collect();
+        return Ok(results.to_object(py));
+    }
+
+    if let Ok(iterable) = value.downcast::<PyTuple>() {
+        let string_values: Vec<String> = iterable
+            .iter()
+            .map(|val| val.str().unwrap().to_string())
+            .collect();
+        let results: Vec<String> = string_values
+            .into_par_iter()
+            .map(|val| intword_single(val, format, &scheme))
+            .collect();
+        return Ok(PyTuple::new_bound(py, results).to_object(py));
+    }
+
+    // Scalar handling
+    let val_str = if let Ok(s) = value.extract::<String>() {
+        s
+    } else if let Ok(f) = value.extract::<f64>() {
+        f.to_string()
+    } else if let Ok(i) = value.extract::<i64>() {
+        i.to_string()
+    } else if value.is_none() {
+        return Ok("None".to_object(py));
+    } else {
+        let repr_result = value.repr().map(|s| s.to_string());
+        return Ok(repr_result
+            .unwrap_or_else(|_| format!("<unprintable object of type {}>", value.get_type()))
+            .to_object(py));
+    };
+
+    let result = intword_single(val_str, format, &scheme);
+    Ok(result.to_object(py))
+}
+
+const SUPERSCRIPT_DIGITS: [char; 10] = ['⁰', '¹', '²', '³', '⁴', '⁵', '⁶', '⁷', '⁸', '⁹'];
+
+/// Render an exponent as Unicode superscript digits, e.g. `9` -> `"⁹"`,
+/// `-3` -> `"⁻³"`.
+fn superscript_exponent(exponent: i64) -> String {
+    let mut result = String::new();
+    if exponent < 0 {
+        result.push('⁻');
+    }
+    for c in exponent.unsigned_abs().to_string().chars() {
+        let digit = c.to_digit(10).expect("decimal digit") as usize;
+        result.push(SUPERSCRIPT_DIGITS[digit]);
+    }
+    result
+}
+
+/// Arbitrary-precision `scientific` path for exact integers: decompose the
+/// magnitude on the integer's decimal digit count rather than `log10` of a
+/// lossy `f64`, so huge exact powers of ten land on the right exponent.
+fn scientific_from_bigint(n: &BigInt, precision: usize) -> String {
+    let negative_prefix = if n.is_negative() { "-" } else { "" };
+    let abs = n.abs();
+
+    if abs.is_zero() {
+        return format!(
+            "{}0.{} x 10{}",
+            negative_prefix,
+            "0".repeat(precision),
+            superscript_exponent(0)
+        );
+    }
+
+    let mut exponent = abs.to_string().len() as i64 - 1;
+    let mut scaled = round_ratio_bigint(&abs, &BigInt::from(10).pow(exponent as u32), precision);
+
+    // Rounding can push the mantissa up to exactly 10.00..., e.g. "9.995"
+    // rounds to "10.00"; bump the exponent so it renders as "1.00 x 10^(n+1)"
+    // instead of the wrong "10.00 x 10^n".
+    let overflow = BigInt::from(10).pow(precision as u32 + 1);
+    if scaled >= overflow {
+        exponent += 1;
+        scaled = round_ratio_bigint(&abs, &BigInt::from(10).pow(exponent as u32), precision);
+    }
+
+    let mantissa = format_scaled_bigint(&scaled, precision);
+    format!(
+        "{}{} x 10{}",
+        negative_prefix,
+        mantissa,
+        superscript_exponent(exponent)
+    )
+}
+
+/// `scientific` path for non-integer (float) inputs.
+fn scientific_from_float(value: f64, precision: usize) -> String {
+    if value == 0.0 {
+        return format!("{:.*} x 10{}", precision, 0.0, superscript_exponent(0));
+    }
+
+    let negative_prefix = if value.is_sign_negative() { "-" } else { "" };
+    let abs = value.abs();
+
+    let mut exponent = abs.log10().floor() as i64;
+    let mut mantissa = abs / 10f64.powi(exponent as i32);
+
+    // log10 of a value right at (or just under/over, from float error) a
+    // power of ten can land one exponent off; nudge using a direct
+    // comparison against the decomposed mantissa instead of trusting log10.
+    if mantissa < 1.0 {
+        exponent -= 1;
+        mantissa = abs / 10f64.powi(exponent as i32);
+    } else if mantissa >= 10.0 {
+        exponent += 1;
+        mantissa = abs / 10f64.powi(exponent as i32);
+    }
+
+    let mut formatted = format!("{:.*}", precision, mantissa);
+
+    // Rounding the mantissa itself can reach "10.00...", e.g. "9.9996" at
+    // precision 2; bump the exponent so it renders as "1.00 x 10^(n+1)".
+    if parse_value(&formatted).map(|p| p.to_f64()).unwrap_or(mantissa) >= 10.0 {
+        exponent += 1;
+        mantissa = abs / 10f64.powi(exponent as i32);
+        formatted = format!("{:.*}", precision, mantissa);
+    }
+
+    format!(
+        "{}{} x 10{}",
+        negative_prefix,
+        formatted,
+        superscript_exponent(exponent)
+    )
+}
+
+fn scientific_single(val_str: String, precision: usize) -> String {
+    if let Some(normalized) = normalize_special_values(&val_str) {
+        return normalized;
+    }
+
+    let parsed = match parse_value(&val_str) {
+        Some(v) => v,
+        None => return val_str,
+    };
+
+    match parsed {
+        ParsedNumber::Integer(n) => scientific_from_bigint(&n, precision),
+        ParsedNumber::Float(f) => {
+            if !f.is_finite() {
+                return format_not_finite(f);
+            }
+            scientific_from_float(f, precision)
+        }
+    }
+}
+
+/// Rust version of a `scientific` formatter: render a value as mantissa × 10^exponent.
+///
+/// Examples
+/// --------
+/// >>> import _fast
+/// >>> _fast.scientific("1900000")
+/// '1.90 x 10⁶'
+/// >>> _fast.scientific(1234.5, 1)
+/// '1.2 x 10³'
+/// >>> _fast.scientific("999999999999999999999999999999999", 2)
+/// '1.00 x 10³³'
+/// >>> _fast.scientific([100, "1900000"], 1)
+/// ['1.0 x 10²', '1.9 x 10⁶']
+#[pyfunction(signature = (value, precision=2))]
+pub fn scientific(
+    py: Python<'_>,
+    value: &Bound<'_, PyAny>,
+    precision: usize,
+) -> PyResult<PyObject> {
+    if let Ok(iterable) = value.downcast::<PyList>() {
+        let string_values: Vec<String> = iterable
+            .iter()
+            .map(|val| val.str().unwrap().to_string())
+            .collect();
+        let results: Vec<String> = string_values
+            .into_par_iter()
+            .map(|val| scientific_single(val, precision))
             .collect();
         return Ok(results.to_object(py));
     }
@@ -261,7 +576,7 @@ pub fn intword(py: Python<'_>, value: &Bound<'_, PyAny>, format: &str) -> PyResu
             .collect();
         let results: Vec<String> = string_values
             .into_par_iter()
-            .map(|val| intword_single(val, format))
+            .map(|val| scientific_single(val, precision))
             .collect();
         return Ok(PyTuple::new_bound(py, results).to_object(py));
     }
@@ -282,6 +597,41 @@ pub fn intword(py: Python<'_>, value: &Bound<'_, PyAny>, format: &str) -> PyResu
             .to_object(py));
     };
 
-    let result = intword_single(val_str, format);
+    let result = scientific_single(val_str, precision);
     Ok(result.to_object(py))
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn intword_promotes_to_next_tier_on_rounding_overflow() {
+        let n = BigInt::from(999_950);
+        assert_eq!(
+            intword_single_bigint(&n, "%.1f", &GroupingScheme::default()),
+            "1.0 million"
+        );
+    }
+
+    #[test]
+    fn intword_does_not_promote_just_under_the_boundary() {
+        let n = BigInt::from(999_949);
+        assert_eq!(
+            intword_single_bigint(&n, "%.1f", &GroupingScheme::default()),
+            "999.9 thousand"
+        );
+    }
+
+    #[test]
+    fn scientific_from_bigint_promotes_exponent_on_rounding_overflow() {
+        let nines: BigInt = "9".repeat(33).parse().unwrap();
+        assert_eq!(scientific_from_bigint(&nines, 2), "1.00 x 10³³");
+    }
+
+    #[test]
+    fn scientific_from_bigint_handles_exact_power_of_ten() {
+        let n = BigInt::from(10).pow(33);
+        assert_eq!(scientific_from_bigint(&n, 2), "1.00 x 10³³");
+    }
+}